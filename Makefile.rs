@@ -1,24 +1,49 @@
 # Makefile for Ring-Switching System Library
 
 CC = gcc
-CFLAGS = -Wall -Wextra -O3 -march=native
-LDFLAGS = -lcrypto -lm
+# No -march=native: rs_mats.c dispatches to an AVX2/NEON kernel at
+# runtime and falls back to the scalar one otherwise, but that only
+# works if the baseline build (including the dispatcher and fallback
+# themselves) doesn't already require those extensions to launch.
+# rs_mats_avx2.c opts its kernel into AVX2 per-function instead.
+CFLAGS = -Wall -Wextra -O3
+LDFLAGS = -lm
+
+# CRYPTO_PROVIDER selects which backend supplies the primitives the PRF
+# seeds from: `openssl` (default) links libcrypto, `builtin` compiles
+# in a self-contained implementation and adds no external dependency.
+CRYPTO_PROVIDER ?= openssl
+
+ifeq ($(CRYPTO_PROVIDER),openssl)
+  LDFLAGS += -lcrypto
+  CRYPTO_SRC = rs_crypto_openssl.c
+else ifeq ($(CRYPTO_PROVIDER),builtin)
+  CFLAGS += -DRS_CRYPTO_BUILTIN
+  CRYPTO_SRC = rs_crypto_builtin.c
+else
+  $(error Unknown CRYPTO_PROVIDER '$(CRYPTO_PROVIDER)', expected 'openssl' or 'builtin')
+endif
 
 # Source files
-SRCS = rs_prf.c rs_params.c rs_mats.c rs_lwr.c rs_test.c
+#
+# rs_mats_avx2.c / rs_mats_neon.c are always compiled in: each is a
+# no-op translation unit on the ISA it doesn't target (guarded by
+# #if), and the right kernel is picked at runtime in rs_mats.c via
+# CPUID / getauxval, not at build time.
+SRCS = rs_prf.c rs_params.c rs_mats.c rs_mats_avx2.c rs_mats_neon.c rs_lwr.c $(CRYPTO_SRC) rs_test.c
 OBJS = $(SRCS:.c=.o)
 
 # Headers
-HEADERS = rs_config.h rs_prf.h rs_params.h rs_mats.h rs_lwr.h
+HEADERS = rs_config.h rs_prf.h rs_params.h rs_mats.h rs_mats_kernel.h rs_lwr.h rs_crypto.h
 
 # Target executable
-TARGET = rs_test
+TEST_BIN = rs_test
 
 # Default target
-all: $(TARGET)
+all: $(TEST_BIN)
 
 # Link
-$(TARGET): $(OBJS)
+$(TEST_BIN): $(OBJS)
 	$(CC) $(CFLAGS) -o $@ $^ $(LDFLAGS)
 
 # Compile
@@ -27,10 +52,71 @@ $(TARGET): $(OBJS)
 
 # Clean
 clean:
-	rm -f $(OBJS) $(TARGET)
+	rm -f $(OBJS) $(TEST_BIN) $(EMBED_OBJS) $(EMBED_TARGET)
 
 # Run tests
-test: $(TARGET)
-	./$(TARGET)
+test: $(TEST_BIN)
+	./$(TEST_BIN)
+
+# Run the dudect-style timing leakage check for rs_lwr_round
+test-timing: $(TEST_BIN)
+	./$(TEST_BIN) --timing
+
+# Rebuild with CRYPTO_PROVIDER=openssl, regenerate the checked-in KAT
+# vectors and diff them against rs_test.kat, then rebuild with
+# CRYPTO_PROVIDER=builtin and confirm it reproduces the exact same
+# vectors byte-for-byte. This is the regression net the constant-time
+# and SIMD rewrites above rely on.
+test-kat:
+	$(MAKE) -f $(firstword $(MAKEFILE_LIST)) clean >/dev/null
+	$(MAKE) -f $(firstword $(MAKEFILE_LIST)) CRYPTO_PROVIDER=openssl $(TEST_BIN) >/dev/null
+	./$(TEST_BIN) --kat-gen test_output.txt
+	diff rs_test.kat test_output.txt
+	$(MAKE) -f $(firstword $(MAKEFILE_LIST)) clean >/dev/null
+	$(MAKE) -f $(firstword $(MAKEFILE_LIST)) CRYPTO_PROVIDER=builtin $(TEST_BIN) >/dev/null
+	./$(TEST_BIN) --kat-gen test_output.txt
+	diff rs_test.kat test_output.txt
+	@echo "test-kat: openssl and builtin backends agree on all vectors"
+
+# --- Embedded / freestanding profile ------------------------------------
+#
+# `make embedded [TARGET=arm-none-eabi] [EMBED_CRYPTO_PROVIDER=openssl]`
+# cross-builds librs_prf.a instead of the rs_test executable: no
+# -march=native (so the result actually cross-compiles), no libm
+# (RS_EMBEDDED swaps rs_params.c's log2 for an integer clz-based
+# routine, and the PRF API for caller-provided scratch buffers instead
+# of a heap-allocated matrix), and it honors TARGET for cross
+# toolchains. Defaults to the bundled crypto backend since a
+# cross-compiled libcrypto is rarely available.
+ifdef TARGET
+  EMBED_CC = $(TARGET)-gcc
+  EMBED_AR = $(TARGET)-ar
+else
+  EMBED_CC = gcc
+  EMBED_AR = ar
+endif
+
+EMBED_CRYPTO_PROVIDER ?= builtin
+ifeq ($(EMBED_CRYPTO_PROVIDER),builtin)
+  EMBED_CFLAGS = -DRS_CRYPTO_BUILTIN
+  EMBED_CRYPTO_SRC = rs_crypto_builtin.c
+else ifeq ($(EMBED_CRYPTO_PROVIDER),openssl)
+  EMBED_CRYPTO_SRC = rs_crypto_openssl.c
+else
+  $(error Unknown EMBED_CRYPTO_PROVIDER '$(EMBED_CRYPTO_PROVIDER)', expected 'openssl' or 'builtin')
+endif
+
+EMBED_CFLAGS += -Wall -Wextra -O2 -ffreestanding -DRS_EMBEDDED
+EMBED_SRCS = rs_prf.c rs_params.c rs_mats.c rs_mats_avx2.c rs_mats_neon.c rs_lwr.c $(EMBED_CRYPTO_SRC)
+EMBED_OBJS = $(EMBED_SRCS:.c=.embedded.o)
+EMBED_TARGET = librs_prf.a
+
+%.embedded.o: %.c $(HEADERS)
+	$(EMBED_CC) $(EMBED_CFLAGS) -c $< -o $@
+
+$(EMBED_TARGET): $(EMBED_OBJS)
+	$(EMBED_AR) rcs $@ $(EMBED_OBJS)
+
+embedded: $(EMBED_TARGET)
 
-.PHONY: all clean test
+.PHONY: all clean test test-timing test-kat embedded
@@ -0,0 +1,61 @@
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    let crypto_provider = env::var("RS_CRYPTO_PROVIDER").unwrap_or_else(|_| "openssl".to_string());
+
+    let mut build = cc::Build::new();
+    build
+        .file("rs_prf.c")
+        .file("rs_params.c")
+        .file("rs_mats.c")
+        .file("rs_mats_avx2.c")
+        .file("rs_mats_neon.c")
+        .file("rs_lwr.c")
+        .include(".")
+        .warnings(true);
+
+    if crypto_provider == "openssl" {
+        build.file("rs_crypto_openssl.c");
+        println!("cargo:rustc-link-lib=crypto");
+    } else if crypto_provider == "builtin" {
+        build.define("RS_CRYPTO_BUILTIN", None).file("rs_crypto_builtin.c");
+    } else {
+        panic!("unknown RS_CRYPTO_PROVIDER '{crypto_provider}', expected 'openssl' or 'builtin'");
+    }
+
+    build.compile("rs_prf");
+
+    println!("cargo:rerun-if-changed=rs_prf.c");
+    println!("cargo:rerun-if-changed=rs_prf.h");
+    println!("cargo:rerun-if-changed=rs_params.c");
+    println!("cargo:rerun-if-changed=rs_params.h");
+    println!("cargo:rerun-if-changed=rs_mats.c");
+    println!("cargo:rerun-if-changed=rs_mats.h");
+    println!("cargo:rerun-if-changed=rs_mats_kernel.h");
+    println!("cargo:rerun-if-changed=rs_mats_avx2.c");
+    println!("cargo:rerun-if-changed=rs_mats_neon.c");
+    println!("cargo:rerun-if-changed=rs_lwr.c");
+    println!("cargo:rerun-if-changed=rs_lwr.h");
+    println!("cargo:rerun-if-changed=rs_config.h");
+    println!("cargo:rerun-if-changed=rs_crypto.h");
+    println!("cargo:rerun-if-changed=rs_crypto_openssl.c");
+    println!("cargo:rerun-if-changed=rs_crypto_builtin.c");
+    println!("cargo:rerun-if-env-changed=RS_CRYPTO_PROVIDER");
+
+    let bindings = bindgen::Builder::default()
+        .header("rs_prf.h")
+        .header("rs_params.h")
+        .clang_arg("-I.")
+        .allowlist_function("rs_prf_.*")
+        .allowlist_function("rs_params_.*")
+        .allowlist_type("rs_.*")
+        .allowlist_var("RS_.*")
+        .generate()
+        .expect("failed to generate bindings for rs_prf.h");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    bindings
+        .write_to_file(out_path.join("bindings.rs"))
+        .expect("failed to write bindings.rs");
+}
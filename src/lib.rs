@@ -0,0 +1,82 @@
+//! Safe Rust wrapper around the `rs_prf` C library: a pseudorandom
+//! function built from a Learning-With-Rounding (LWR) instance, with
+//! key material expanded from a seed and zeroized on drop.
+//!
+//! ```no_run
+//! use rs_prf::{PrfContext, PrfKey};
+//!
+//! let key = PrfKey::generate(&[0u8; rs_prf::SEED_BYTES]);
+//! let ctx = PrfContext::new([1u8; rs_prf::SEED_BYTES]);
+//! let output = ctx.evaluate(&key);
+//! assert_eq!(output.len(), rs_prf::OUTPUT_BYTES);
+//! ```
+
+mod sys;
+
+use zeroize::Zeroize;
+
+/// Length in bytes of a key seed or a per-call context seed.
+pub const SEED_BYTES: usize = sys::RS_SEED_BYTES as usize;
+
+/// Length in bytes of one PRF evaluation's output.
+pub const OUTPUT_BYTES: usize = sys::RS_PRF_OUTPUT_BYTES as usize;
+
+/// A PRF secret key, expanded from a seed. Key material is held in a
+/// heap allocation owned by this struct and is zeroized when it is
+/// dropped.
+pub struct PrfKey {
+    inner: Box<sys::rs_prf_key_t>,
+}
+
+impl PrfKey {
+    /// Expands `seed` into a fresh secret key.
+    pub fn generate(seed: &[u8; SEED_BYTES]) -> Self {
+        // SAFETY: `inner` is a valid, zero-initialized `rs_prf_key_t`
+        // and `rs_prf_keygen` only writes within it.
+        let mut inner = Box::new(unsafe { std::mem::zeroed::<sys::rs_prf_key_t>() });
+        unsafe {
+            sys::rs_prf_keygen(inner.as_mut(), seed.as_ptr());
+        }
+        PrfKey { inner }
+    }
+}
+
+impl Drop for PrfKey {
+    fn drop(&mut self) {
+        // SAFETY: `inner` is a valid `rs_prf_key_t` for the lifetime of `self`.
+        unsafe {
+            sys::rs_prf_key_zeroize(self.inner.as_mut());
+        }
+    }
+}
+
+/// A per-evaluation context seed. Unlike [`PrfKey`] this is public
+/// input, but it is still wrapped so callers go through `evaluate`
+/// rather than the raw FFI signature.
+pub struct PrfContext {
+    seed: [u8; SEED_BYTES],
+}
+
+impl PrfContext {
+    /// Creates a context from a caller-chosen seed (e.g. a nonce).
+    pub fn new(seed: [u8; SEED_BYTES]) -> Self {
+        PrfContext { seed }
+    }
+
+    /// Evaluates the PRF at this context under `key`.
+    pub fn evaluate(&self, key: &PrfKey) -> [u8; OUTPUT_BYTES] {
+        let mut out = [0u8; OUTPUT_BYTES];
+        // SAFETY: `out` and `self.seed` are both correctly sized buffers
+        // for the underlying C function, and `key.inner` is valid.
+        unsafe {
+            sys::rs_prf_eval(out.as_mut_ptr(), key.inner.as_ref(), self.seed.as_ptr());
+        }
+        out
+    }
+}
+
+impl Drop for PrfContext {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
@@ -0,0 +1,7 @@
+//! Raw, unsafe FFI declarations generated by `bindgen` from `rs_prf.h` /
+//! `rs_params.h`. Nothing here should be used directly outside this
+//! crate; see [`crate::PrfKey`] and [`crate::PrfContext`] for the safe
+//! surface.
+#![allow(non_camel_case_types, non_snake_case, dead_code)]
+
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));